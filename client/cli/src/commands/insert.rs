@@ -17,16 +17,24 @@
 
 //! Implementation of the `insert` subcommand
 
-use crate::{Error, CliConfiguration, KeystoreParams, with_crypto_scheme, CryptoSchemeFlag, SharedParams, utils};
+use crate::{
+	Error, CliConfiguration, KeystoreParams, with_crypto_scheme, CryptoScheme, CryptoSchemeFlag,
+	SharedParams, utils,
+};
 use structopt::StructOpt;
-use sp_core::{crypto::KeyTypeId, Bytes};
+use sp_core::{crypto::KeyTypeId, hashing::keccak_256, Bytes};
 use std::convert::TryFrom;
-use futures01::Future;
+use std::{path::PathBuf, str::FromStr};
+use futures01::{stream, Future, Stream};
 use hyper::rt;
 use sc_rpc::author::AuthorClient;
 use jsonrpc_core_client::transports::http;
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sp_core::crypto::ExposeSecret;
+use bip39::{Language, Mnemonic, MnemonicType};
+use sc_service::config::KeystoreConfig;
+use sc_keystore::LocalKeystore;
+use sp_keystore::SyncCryptoStore;
 
 /// The `insert` command
 #[derive(Debug, StructOpt)]
@@ -41,14 +49,34 @@ pub struct InsertCmd {
 	#[structopt(long)]
 	suri: Option<String>,
 
+	/// Generate a fresh BIP39 mnemonic instead of reading one from `--suri`, print it once for
+	/// backup, and insert the key derived from it. Lets a node be bootstrapped without a prior
+	/// `key generate` step. Ignored if `--keys` is given.
+	#[structopt(long, conflicts_with = "suri")]
+	generate: bool,
+
+	/// Path to a JSON manifest of `{key_type, scheme, derivation_path}` entries that share one
+	/// freshly generated mnemonic, e.g. a validator's full `gran`/`babe`/`imon`/`audi` session
+	/// key set. Each entry is derived from the mnemonic and inserted via its own
+	/// `author_insertKey` call. Takes precedence over `--suri`/`--generate`/`--key-type`.
+	#[structopt(long, parse(from_os_str), conflicts_with_all = &["suri", "key-type"])]
+	keys: Option<PathBuf>,
+
 	/// Key type, examples: "gran", or "imon"
-	#[structopt(long)]
-	key_type: String,
+	#[structopt(long, required_unless = "keys")]
+	key_type: Option<String>,
 
 	/// Node JSON-RPC endpoint, default "http://localhost:9933"
 	#[structopt(long)]
 	node_url: Option<String>,
 
+	/// Write the key directly into the node's on-disk keystore instead of dialing
+	/// `author_insertKey` over RPC. Useful for air-gapped validator provisioning where the
+	/// node process has not started yet. Requires a keystore path resolvable from
+	/// `--base-path`/`--keystore-path`. Does not change the default RPC behaviour when omitted.
+	#[structopt(long)]
+	offline: bool,
+
 	#[allow(missing_docs)]
 	#[structopt(flatten)]
 	pub keystore_params: KeystoreParams,
@@ -68,19 +96,38 @@ impl InsertCmd {
 		where
 			H: DeserializeOwned + Serialize + Send + Sync + 'static,
 	{
-		let suri = utils::read_uri(self.suri.as_ref())?;
+		let node_url = self.node_url.as_ref()
+			.map(String::as_str)
+			.unwrap_or("http://localhost:9933");
 		let password = self.keystore_params.read_password()?;
 		let password = password.as_ref().map(|s| s.expose_secret().as_str());
 
+		if let Some(manifest) = &self.keys {
+			return self.run_batch::<H>(manifest, node_url, password);
+		}
+
+		let suri = if self.generate {
+			generate_mnemonic().phrase().to_string()
+		} else {
+			utils::read_uri(self.suri.as_ref())?
+		};
+
 		let public = with_crypto_scheme!(
 			self.crypto_scheme.scheme,
 			to_vec(&suri, password)
 		)?;
 
-		let node_url = self.node_url.as_ref()
-			.map(String::as_str)
-			.unwrap_or("http://localhost:9933");
-		let key_type = &self.key_type;
+		// ECDSA keys back EVM-compatible chains, whose on-chain account is the Keccak-256
+		// derived Ethereum-style address rather than the raw public key. Surface it here so
+		// operators seeding session/offence keys for an EVM-bridged chain can record it.
+		if self.crypto_scheme.scheme == CryptoScheme::Ecdsa {
+			let address = ethereum_address(&suri, password)?;
+			println!("Ethereum address: 0x{}", hex::encode(address));
+		}
+
+		// `--keys` is mutually exclusive with `--key-type`, so this is only reachable with it set.
+		let key_type = self.key_type.as_ref()
+			.expect("key_type is required unless --keys is given, which returned above; qed");
 
 		// Just checking
 		let _key_type_id = KeyTypeId::try_from(key_type.as_str())
@@ -88,13 +135,96 @@ impl InsertCmd {
 				Error::Other("Cannot convert argument to keytype: argument should be 4-character string".into())
 			})?;
 
+		self.insert::<H>(node_url, vec![(key_type.to_string(), suri, sp_core::Bytes(public))])
+	}
+
+	/// Derive every entry of a shared-mnemonic manifest and insert them in a single session.
+	fn run_batch<H>(&self, manifest: &PathBuf, node_url: &str, password: Option<&str>) -> Result<(), Error>
+		where
+			H: DeserializeOwned + Serialize + Send + Sync + 'static,
+	{
+		let manifest = std::fs::read_to_string(manifest)
+			.map_err(|e| Error::Other(format!("Failed to read key manifest: {}", e)))?;
+		let specs: Vec<KeySpec> = serde_json::from_str(&manifest)
+			.map_err(|e| Error::Other(format!("Failed to parse key manifest: {}", e)))?;
+
+		let mnemonic = generate_mnemonic();
 
-		insert_key::<H>(
-			&node_url,
-			key_type.to_string(),
-			suri,
-			sp_core::Bytes(public),
-		);
+		let mut keys = Vec::with_capacity(specs.len());
+		for spec in &specs {
+			let _key_type_id = KeyTypeId::try_from(spec.key_type.as_str())
+				.map_err(|_| {
+					Error::Other(format!(
+						"Cannot convert `{}` to keytype: should be a 4-character string",
+						spec.key_type,
+					))
+				})?;
+			let scheme = CryptoScheme::from_str(&spec.scheme)
+				.map_err(|_| Error::Other(format!("Unknown crypto scheme `{}`", spec.scheme)))?;
+			let suri = match &spec.derivation_path {
+				Some(path) => format!("{}{}", mnemonic.phrase(), path),
+				None => mnemonic.phrase().to_string(),
+			};
+			let public = with_crypto_scheme!(scheme, to_vec(&suri, password))?;
+
+			// ECDSA keys back EVM-compatible chains, whose on-chain account is the Keccak-256
+			// derived Ethereum-style address rather than the raw public key. Surface it here so
+			// operators seeding session/offence keys for an EVM-bridged chain can record it.
+			if scheme == CryptoScheme::Ecdsa {
+				let address = ethereum_address(&suri, password)?;
+				println!("Ethereum address for `{}`: 0x{}", spec.key_type, hex::encode(address));
+			}
+
+			keys.push((spec.key_type.clone(), suri, sp_core::Bytes(public)));
+		}
+
+		self.insert::<H>(node_url, keys)
+	}
+
+	/// Insert `keys` either over RPC or, when offline, directly into the local keystore.
+	fn insert<H>(&self, node_url: &str, keys: Vec<(String, String, Bytes)>) -> Result<(), Error>
+		where
+			H: DeserializeOwned + Serialize + Send + Sync + 'static,
+	{
+		if self.offline {
+			self.insert_offline(keys)
+		} else {
+			insert_keys::<H>(node_url, keys);
+			Ok(())
+		}
+	}
+
+	/// Open the node's on-disk keystore directly and persist `keys` there, bypassing the RPC
+	/// roundtrip entirely so a key can be seeded before the node process is even started.
+	fn insert_offline(&self, keys: Vec<(String, String, Bytes)>) -> Result<(), Error> {
+		let base_path = self.shared_params.base_path()
+			.ok_or_else(|| Error::Other(
+				"Offline insertion needs a keystore path: pass --base-path or --keystore-path"
+					.into(),
+			))?;
+		let keystore = match self.keystore_params.keystore_config(&base_path)? {
+			KeystoreConfig::Path { path, password } => LocalKeystore::open(path, password)
+				.map_err(|e| Error::Other(format!("Failed to open keystore: {}", e)))?,
+			KeystoreConfig::InMemory => return Err(Error::Other(
+				"Offline insertion requires an on-disk keystore, not an in-memory one".into(),
+			)),
+		};
+
+		for (key_type, suri, public) in keys {
+			let key_type_id = KeyTypeId::try_from(key_type.as_str())
+				.map_err(|_| {
+					Error::Other(
+						"Cannot convert argument to keytype: argument should be 4-character string"
+							.into(),
+					)
+				})?;
+			SyncCryptoStore::insert_unknown(&keystore, key_type_id, &suri, &public.0)
+				.map_err(|_| Error::Other(format!(
+					"Failed to insert key into keystore: {}",
+					key_type,
+				)))?;
+			println!("Inserted key into keystore. Public key (hex): 0x{}", hex::encode(&public.0));
+		}
 
 		Ok(())
 	}
@@ -110,19 +240,63 @@ impl CliConfiguration for InsertCmd {
 	}
 }
 
+/// One entry of a `--keys` manifest: a session key to derive from the shared mnemonic and
+/// insert, e.g. `{"key_type": "gran", "scheme": "ed25519", "derivation_path": "//0"}`.
+#[derive(Deserialize)]
+struct KeySpec {
+	key_type: String,
+	scheme: String,
+	derivation_path: Option<String>,
+}
+
+/// Generate a fresh BIP39 mnemonic and print it once for backup before it is derived from.
+fn generate_mnemonic() -> Mnemonic {
+	let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+	println!(
+		"Generated mnemonic, write it down now -- it will not be shown again:\n{}",
+		mnemonic.phrase(),
+	);
+	mnemonic
+}
+
 fn to_vec<P: sp_core::Pair>(uri: &str, pass: Option<&str>) -> Result<Vec<u8>, Error> {
 	let p = utils::pair_from_suri::<P>(uri, pass)?;
 	Ok(p.public().as_ref().to_vec())
 }
 
-fn insert_key<H>(url: &str, key_type: String, suri: String, public: Bytes)
+/// Derive the Ethereum-style account address for an ECDSA key, mirroring the `fp-account`
+/// convention used by EVM-bridged chains: Keccak-256 of the uncompressed public key, keeping
+/// the last 20 bytes.
+fn ethereum_address(uri: &str, pass: Option<&str>) -> Result<[u8; 20], Error> {
+	let pair = utils::pair_from_suri::<sp_core::ecdsa::Pair>(uri, pass)?;
+	let decompressed = libsecp256k1::PublicKey::parse_slice(
+		&pair.public().0,
+		Some(libsecp256k1::PublicKeyFormat::Compressed),
+	)
+		.map_err(|_| Error::Other("Invalid ECDSA public key".into()))?
+		.serialize();
+
+	let mut uncompressed = [0u8; 64];
+	uncompressed.copy_from_slice(&decompressed[1 .. 65]);
+	let hash = keccak_256(&uncompressed);
+
+	let mut address = [0u8; 20];
+	address.copy_from_slice(&hash[12 ..]);
+	Ok(address)
+}
+
+/// Insert one or more keys over a single `author_insertKey` RPC session.
+fn insert_keys<H>(url: &str, keys: Vec<(String, String, Bytes)>)
 	where
 		H: DeserializeOwned + Serialize + Send + Sync + 'static,
 {
 	rt::run(
 		http::connect(&url)
 			.and_then(|client: AuthorClient<H, H>| {
-				client.insert_key(key_type, suri, public).map(|_| ())
+				stream::iter_ok(keys)
+					.for_each(move |(key_type, suri, public)| {
+						client.insert_key(key_type, suri, public).map(|_| ())
+					})
 			})
 			.map_err(|e| {
 				println!("Error inserting key: {:?}", e);
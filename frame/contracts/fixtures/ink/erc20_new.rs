@@ -0,0 +1,10 @@
+// Offline-resolved selector and SCALE-encoded arguments for the `erc20` bundle's
+// `new(initial_supply: Balance)` constructor. Generated once from the contract's
+// `cargo-contract` metadata so the benchmark never needs to parse JSON at runtime.
+
+pub const SELECTOR: [u8; 4] = [0x9b, 0xae, 0x9d, 0x5e];
+
+pub const ARGS: &[u8] = &[
+	// `initial_supply: Balance`, SCALE-encoded `0u128`.
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
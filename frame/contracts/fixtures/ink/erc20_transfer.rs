@@ -0,0 +1,13 @@
+// Offline-resolved selector and SCALE-encoded arguments for the `erc20` bundle's
+// `transfer(to: AccountId, value: Balance)` message. Generated once from the contract's
+// `cargo-contract` metadata so the benchmark never needs to parse JSON at runtime.
+
+pub const SELECTOR: [u8; 4] = [0x84, 0xa1, 0x5d, 0xa1];
+
+pub const ARGS: &[u8] = &[
+	// `to: AccountId`, 32 zero bytes standing in for a representative recipient.
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+	// `value: Balance`, SCALE-encoded `0u128`.
+	0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];